@@ -1,6 +1,6 @@
 use anyhow::Result;
 use clap::Parser;
-use rusty_notion::api::{self, Client, ClientParameters};
+use rusty_notion::{query_database_properties, NotionApiClient, NotionApiClientParameters};
 use serde_json::Value as Json;
 
 #[derive(Parser)]
@@ -18,12 +18,12 @@ fn main() -> Result<()> {
         database_id,
     } = Cli::parse();
 
-    let client = Client::new(ClientParameters {
+    let client = NotionApiClient::new(NotionApiClientParameters {
         api_key,
         base_url_override: None,
     });
 
-    let response = api::query_database_properties(&client, &database_id)?;
+    let response = query_database_properties(&client, &database_id)?;
 
     println!("StatusCode : {}", response.status());
     println!("Content    : {}", response.into_json::<Json>()?);
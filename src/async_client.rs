@@ -0,0 +1,313 @@
+use crate::retry::{DefaultRetryPolicy, RetryConfig, RetryPolicy};
+use crate::{
+    CreateDatabaseEntryParameters, NotionApiClientError, NotionApiClientParameters,
+    NotionApiClientResult, NotionErrorBody, QueryDatabaseParameters, UpdateDatabaseEntryParameters,
+};
+use serde_json::Value as Json;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+/// An async counterpart to [`crate::NotionApiClient`], built on `reqwest` instead of
+/// `ureq`, for callers embedded in an async runtime who would otherwise need
+/// `spawn_blocking` wrappers around the synchronous client. Shares the same
+/// `NotionApiClientParameters`, error classification, and retry/backoff logic as
+/// the sync client, via [`DefaultRetryPolicy`].
+pub struct AsyncClient {
+    inner: reqwest::Client,
+    base_url: String,
+    api_key: String,
+}
+
+impl AsyncClient {
+    pub fn base_url(self, base_url: String) -> Self {
+        Self { base_url, ..self }
+    }
+
+    pub fn new(parameters: NotionApiClientParameters) -> Self {
+        let NotionApiClientParameters {
+            api_key,
+            base_url_override,
+        } = parameters;
+
+        let base_url = base_url_override.unwrap_or_else(|| "https://api.notion.com/v1".to_string());
+
+        Self {
+            api_key,
+            inner: reqwest::Client::new(),
+            base_url,
+        }
+    }
+}
+
+pub async fn create_database_entry(
+    client: &AsyncClient,
+    parameters: CreateDatabaseEntryParameters<'_>,
+) -> NotionApiClientResult<Json> {
+    let CreateDatabaseEntryParameters {
+        database_id,
+        properties,
+    } = parameters;
+
+    let path = format!("{}/pages", &client.base_url);
+
+    let body = serde_json::json!({
+        "parent": { "database_id": database_id },
+        "properties": properties,
+    });
+
+    send_with_retries(client, || client.inner.post(&path).json(&body)).await
+}
+
+pub async fn query_database(
+    client: &AsyncClient,
+    parameters: QueryDatabaseParameters<'_>,
+) -> NotionApiClientResult<Json> {
+    let QueryDatabaseParameters {
+        database_id,
+        start_cursor,
+        page_size,
+        filter,
+    } = parameters;
+
+    let page_size = page_size
+        .unwrap_or(unsafe { NonZeroU32::new_unchecked(100) })
+        .get();
+
+    let path = format!("{}/databases/{}/query", &client.base_url, database_id);
+    let mut body = serde_json::json!({"page_size": page_size});
+
+    if let Some(start_cursor) = start_cursor {
+        body["start_cursor"] = start_cursor.into();
+    }
+
+    if let Some(filter) = filter {
+        body["filter"] = filter;
+    }
+
+    send_with_retries(client, || client.inner.post(&path).json(&body)).await
+}
+
+pub async fn update_database_entry(
+    client: &AsyncClient,
+    parameters: UpdateDatabaseEntryParameters<'_>,
+) -> NotionApiClientResult<Json> {
+    let UpdateDatabaseEntryParameters {
+        entry_id,
+        properties,
+    } = parameters;
+
+    let path = format!("{}/pages/{}", &client.base_url, entry_id);
+    let body = serde_json::json!({"properties": properties});
+
+    send_with_retries(client, || client.inner.patch(&path).json(&body)).await
+}
+
+/// The async equivalent of [`crate::send_with_retries`]: same [`RetryPolicy`] and
+/// backoff curve, but driven by `tokio::time::sleep` instead of a blocking sleep,
+/// since this client can't afford to park the executor thread between attempts.
+async fn send_with_retries(
+    client: &AsyncClient,
+    build_request: impl Fn() -> reqwest::RequestBuilder,
+) -> NotionApiClientResult<Json> {
+    let config = RetryConfig::<DefaultRetryPolicy>::default();
+    let mut attempt = 0;
+
+    loop {
+        let err = match send(client, build_request()).await {
+            Ok(json) => return Ok(json),
+            Err(err) => err,
+        };
+
+        if attempt >= config.max_retries || !config.policy.should_retry(&err) {
+            tracing::warn!("Not retrying Notion API request error: {}", err);
+
+            return Err(err);
+        }
+
+        let delay = config
+            .policy
+            .backoff_hint(&err)
+            .unwrap_or_else(|| config.backoff_for_attempt(attempt));
+
+        tracing::warn!(
+            "Sleeping for {:?} before retrying Notion API request",
+            delay
+        );
+
+        tokio::time::sleep(delay).await;
+
+        attempt += 1;
+    }
+}
+
+async fn send(client: &AsyncClient, request: reqwest::RequestBuilder) -> NotionApiClientResult<Json> {
+    let request = request
+        .header("Content-Type", "application/json")
+        .header("Notion-Version", "2022-06-28")
+        .bearer_auth(&client.api_key);
+
+    let response = request
+        .send()
+        .await
+        .map_err(|err| NotionApiClientError::Transport(err.to_string()))?;
+
+    let status = response.status().as_u16();
+
+    if status == 429 {
+        let retry_after = response
+            .headers()
+            .get("Retry-After")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_else(|| {
+                tracing::warn!(
+                    "Notion API response returned 429 status code without Retry-After header"
+                );
+
+                "1.0"
+            })
+            .parse::<f64>()
+            .unwrap_or_else(|_value| {
+                tracing::warn!("Notion API response returned 429 status code with invalid Retry-After header");
+
+                1.0
+            });
+
+        return Err(NotionApiClientError::RateLimit(Duration::from_secs_f64(
+            retry_after,
+        )));
+    }
+
+    if status >= 400 {
+        let body = response.json::<NotionErrorBody>().await.ok();
+
+        return Err(NotionApiClientError::Status(status, body));
+    }
+
+    response
+        .json::<Json>()
+        .await
+        .map_err(|err| NotionApiClientError::Parse(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use httpmock::{Method::POST, MockServer};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_query_database_returns_parsed_json_on_200() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query")
+                .method(POST)
+                .header("Authorization", "Bearer test_api_key")
+                .header("Notion-Version", "2022-06-28");
+
+            then.status(200)
+                .json_body(json!({"object": "list", "results": []}));
+        });
+
+        let client = AsyncClient::new(NotionApiClientParameters {
+            api_key: "test_api_key".to_string(),
+            base_url_override: None,
+        })
+        .base_url(base_url);
+
+        let result = query_database(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .await?;
+
+        mock.assert();
+        assert_eq!(result["object"], "list");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query_database_returns_notion_error_details_on_404() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query").method(POST);
+
+            then.status(404).json_body(json!({
+                "object": "error",
+                "status": 404,
+                "code": "object_not_found",
+                "message": "Could not find database with ID: test_database_id."
+            }));
+        });
+
+        let client = AsyncClient::new(NotionApiClientParameters {
+            api_key: "test_api_key".to_string(),
+            base_url_override: None,
+        })
+        .base_url(base_url);
+
+        let err = query_database(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.is_not_found());
+        assert_eq!(err.notion_code(), Some("object_not_found"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query_database_retries_rate_limits_until_max_retries_is_reached() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query").method(POST);
+            then.status(429).header("Retry-After", "0.001");
+        });
+
+        let client = AsyncClient::new(NotionApiClientParameters {
+            api_key: "test_api_key".to_string(),
+            base_url_override: None,
+        })
+        .base_url(base_url);
+
+        let err = query_database(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.is_rate_limit());
+        mock.assert_hits(RetryConfig::<DefaultRetryPolicy>::default().max_retries as usize + 1);
+
+        Ok(())
+    }
+}
@@ -1,46 +1,84 @@
 use std::time::Duration;
 
+/// The structured error body Notion returns alongside a non-2xx response,
+/// e.g. `{ "object": "error", "status": 404, "code": "object_not_found", "message": "..." }`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NotionErrorBody {
+    pub code: String,
+    pub message: String,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Notion API request failure. Please retry in {0:?}")]
     RateLimit(Duration),
 
-    #[error("Notion API request failed with status code {0}")]
-    Status(u16),
+    #[error("Notion API request failed with status code {0}{}", render_notion_error_body(.1))]
+    Status(u16, Option<NotionErrorBody>),
 
     #[error("Notion API request failure: {0}")]
     Transport(String),
+
+    #[error("Failed to parse Notion API response body: {0}")]
+    Parse(#[from] std::io::Error),
+}
+
+fn render_notion_error_body(body: &Option<NotionErrorBody>) -> String {
+    match body {
+        Some(body) => format!(": {} ({})", body.message, body.code),
+        None => String::new(),
+    }
 }
 
-// Integrations should accommodate variable rate limits by handling HTTP 429 responses
-// and respecting the Retry-After response header value,
-// which is set as an integer number of seconds (in decimal).
-// See more for details https://developers.notion.com/reference/request-limits
-impl From<ureq::Error> for Error {
-    fn from(err: ureq::Error) -> Self {
-        match err {
-            ureq::Error::Transport(err) => Error::Transport(err.to_string()),
-            ureq::Error::Status(429, response) => {
-                let retry_after = response.header("Retry-After").unwrap_or_else(|| {
-                    tracing::warn!(
-                        "Notion API response returned 429 status code without Retry-After header"
-                    );
-
-                    "1.0"
-                });
-
-                let seconds = retry_after.parse::<f64>().unwrap_or_else (|_value| {
-                    tracing::warn!("Notion API response returned 429 status code with invalid Retry-After header: {}", retry_after);
-
-                    1.0
-                });
-
-                let duration = Duration::from_secs_f64(seconds);
-                tracing::warn!("Notion API request rate limited for {:?}", duration);
-
-                Error::RateLimit(duration)
-            }
-            ureq::Error::Status(code, _) => Error::Status(code),
+impl Error {
+    pub fn is_authorization(&self) -> bool {
+        matches!(self, Self::Status(401, _))
+    }
+
+    pub fn is_bad_request(&self) -> bool {
+        matches!(self, Self::Status(400, _))
+    }
+
+    pub fn is_communication(&self) -> bool {
+        matches!(self, Self::Transport(_))
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::Status(409, _))
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::Status(404, _))
+    }
+
+    pub fn is_rate_limit(&self) -> bool {
+        matches!(self, Self::RateLimit(_))
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimit(duration) => Some(*duration),
+            _ => None,
+        }
+    }
+
+    /// The semantic error code Notion reported, e.g. `object_not_found`,
+    /// `validation_error`, `conflict_error`, `unauthorized`. `None` when the
+    /// failure never reached Notion (e.g. a transport error) or the response
+    /// body didn't parse as a Notion error. Also `None` for rate-limit errors:
+    /// the body of a 429 response is not read, only its `Retry-After` header.
+    pub fn notion_code(&self) -> Option<&str> {
+        match self {
+            Self::Status(_, Some(body)) => Some(body.code.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The human-readable message Notion reported alongside `notion_code`.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Status(_, Some(body)) => Some(body.message.as_str()),
+            _ => None,
         }
     }
 }
@@ -61,7 +99,7 @@ mod tests {
 
     #[test]
     fn test_status_code_error_message() {
-        let err = Error::Status(404);
+        let err = Error::Status(404, None);
 
         assert_eq!(
             err.to_string(),
@@ -69,6 +107,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_status_code_error_message_with_notion_error_body() {
+        let err = Error::Status(
+            404,
+            Some(NotionErrorBody {
+                code: "object_not_found".to_string(),
+                message: "Could not find page.".to_string(),
+            }),
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "Notion API request failed with status code 404: Could not find page. (object_not_found)"
+        )
+    }
+
     #[test]
     fn test_transport_error_message() {
         let err = Error::Transport("Cannot resolve the target name.".to_string());
@@ -78,4 +132,39 @@ mod tests {
             "Notion API request failure: Cannot resolve the target name."
         );
     }
+
+    #[test]
+    fn test_notion_code_and_message_are_read_from_the_error_body() {
+        let err = Error::Status(
+            404,
+            Some(NotionErrorBody {
+                code: "object_not_found".to_string(),
+                message: "Could not find page.".to_string(),
+            }),
+        );
+
+        assert!(err.is_not_found());
+        assert_eq!(err.notion_code(), Some("object_not_found"));
+        assert_eq!(err.message(), Some("Could not find page."));
+    }
+
+    #[test]
+    fn test_notion_code_and_message_are_absent_without_a_body() {
+        let err = Error::Status(404, None);
+
+        assert!(err.is_not_found());
+        assert_eq!(err.notion_code(), None);
+        assert_eq!(err.message(), None);
+    }
+
+    #[test]
+    fn test_retry_after_is_only_set_for_rate_limit_errors() {
+        let rate_limited = Error::RateLimit(Duration::from_secs_f64(0.23));
+        assert!(rate_limited.is_rate_limit());
+        assert_eq!(rate_limited.retry_after(), Some(Duration::from_secs_f64(0.23)));
+
+        let transport = Error::Transport("Cannot resolve the target name.".to_string());
+        assert!(transport.is_communication());
+        assert_eq!(transport.retry_after(), None);
+    }
 }
@@ -1,44 +1,187 @@
+#[cfg(feature = "async")]
+mod async_client;
 mod failure;
-mod headers;
+mod models;
 mod parameters;
-
-use headers::{SetAuthorizationHeader, SetDefaultHeaders};
-use std::{num::NonZeroU32, thread, time::Duration};
-use ureq::{Agent, AgentBuilder, Response};
-
+mod retry;
+mod transport;
+
+use std::{
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "async")]
+pub use async_client::{
+    create_database_entry as create_database_entry_async, query_database as query_database_async,
+    update_database_entry as update_database_entry_async, AsyncClient,
+};
 pub use failure::Error as NotionApiClientError;
+pub use failure::NotionErrorBody;
+pub use models::*;
 pub use parameters::*;
+pub use retry::{send_with_retries, DefaultRetryPolicy, RetryConfig, RetryPolicy};
+pub use transport::{HttpMethod, HttpTransport, TransportFailure, TransportResponse, UreqTransport};
 
 pub type NotionApiClientResult<T> = std::result::Result<T, NotionApiClientError>;
 
-pub struct NotionApiClient {
-    inner: Agent,
+/// Mutates the headers of an outgoing request before it's dispatched, e.g. to add
+/// tracing spans, idempotency keys, or route through a custom rate-limit queue.
+pub type Interceptor = Arc<dyn Fn(&mut Vec<(String, String)>) + Send + Sync>;
+
+pub struct NotionApiClient<T = UreqTransport> {
+    transport: T,
     base_url: String,
     api_key: String,
+    interceptor: Option<Interceptor>,
+    throttle: Option<Throttle>,
 }
 
-impl NotionApiClient {
+impl NotionApiClient<UreqTransport> {
     pub fn new(parameters: NotionApiClientParameters) -> Self {
         let NotionApiClientParameters {
             api_key,
             base_url_override,
         } = parameters;
 
-        let inner = AgentBuilder::new().build();
         let base_url = base_url_override.unwrap_or_else(|| "https://api.notion.com/v1".to_string());
 
         Self {
             api_key,
-            inner,
+            transport: UreqTransport::default(),
             base_url,
+            interceptor: None,
+            throttle: None,
         }
     }
 }
 
-pub fn create_database_entry(
-    client: &NotionApiClient,
+impl<T: HttpTransport> NotionApiClient<T> {
+    pub fn with_transport(api_key: String, base_url_override: Option<String>, transport: T) -> Self {
+        let base_url = base_url_override.unwrap_or_else(|| "https://api.notion.com/v1".to_string());
+
+        Self {
+            api_key,
+            transport,
+            base_url,
+            interceptor: None,
+            throttle: None,
+        }
+    }
+
+    pub fn with_interceptor(
+        self,
+        interceptor: impl Fn(&mut Vec<(String, String)>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            interceptor: Some(Arc::new(interceptor)),
+            ..self
+        }
+    }
+
+    /// Opts into proactive client-side rate-limit throttling: before issuing a
+    /// request, the client sleeps for `min_spacing` since the previous request
+    /// if needed, and for the remainder of any cooldown window remembered from
+    /// the last `RateLimit` error, instead of firing the request just to get
+    /// another 429. Off by default, since it adds a blocking sleep to the
+    /// calling thread.
+    pub fn with_rate_limit_throttling(self, min_spacing: Duration) -> Self {
+        Self {
+            throttle: Some(Throttle {
+                min_spacing,
+                state: Mutex::new(ThrottleState::default()),
+            }),
+            ..self
+        }
+    }
+
+    fn default_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Notion-Version".to_string(), "2022-06-28".to_string()),
+            ("Authorization".to_string(), format!("Bearer {}", &self.api_key)),
+        ];
+
+        if let Some(interceptor) = &self.interceptor {
+            interceptor(&mut headers);
+        }
+
+        headers
+    }
+
+    fn wait_for_throttle_slot(&self) {
+        if let Some(throttle) = &self.throttle {
+            throttle.wait_for_slot();
+        }
+    }
+}
+
+/// Tracks request pacing across calls to a single [`NotionApiClient`] so it can
+/// avoid firing requests it already knows will be rate limited.
+struct Throttle {
+    min_spacing: Duration,
+    state: Mutex<ThrottleState>,
+}
+
+#[derive(Default)]
+struct ThrottleState {
+    last_request_at: Option<Instant>,
+    cooldown_until: Option<Instant>,
+}
+
+impl Throttle {
+    fn wait_for_slot(&self) {
+        let wait_until = {
+            let state = self.state.lock().unwrap();
+
+            [
+                state.last_request_at.map(|instant| instant + self.min_spacing),
+                state.cooldown_until,
+            ]
+            .into_iter()
+            .flatten()
+            .max()
+        };
+
+        let now = Instant::now();
+
+        if let Some(wait_until) = wait_until {
+            if let Some(delay) = wait_until.checked_duration_since(now) {
+                tracing::debug!("Throttling Notion API request for {:?}", delay);
+                std::thread::sleep(delay);
+            }
+        }
+    }
+
+    fn record(&self, result: &NotionApiClientResult<TransportResponse>) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        state.last_request_at = Some(now);
+
+        if let Err(NotionApiClientError::RateLimit(retry_after)) = result {
+            state.cooldown_until = Some(now + *retry_after);
+        }
+    }
+}
+
+fn dispatch<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    result: Result<TransportResponse, TransportFailure>,
+) -> NotionApiClientResult<TransportResponse> {
+    let result = result.map_err(api_client_error);
+
+    if let Some(throttle) = &client.throttle {
+        throttle.record(&result);
+    }
+
+    result
+}
+
+pub fn create_database_entry<T: HttpTransport>(
+    client: &NotionApiClient<T>,
     parameters: CreateDatabaseEntryParameters,
-) -> NotionApiClientResult<Response> {
+) -> NotionApiClientResult<TransportResponse> {
     let CreateDatabaseEntryParameters {
         database_id,
         properties,
@@ -51,34 +194,30 @@ pub fn create_database_entry(
         "properties": properties,
     });
 
-    client
-        .inner
-        .post(&path)
-        .set_default_headers()
-        .set_authorization_header(&client.api_key)
-        .send_json(body)
-        .map_err(api_client_error)
+    client.wait_for_throttle_slot();
+    let result = client
+        .transport
+        .send_json(HttpMethod::Post, &path, &client.default_headers(), body);
+
+    dispatch(client, result)
 }
 
-pub fn query_database_properties(
-    client: &NotionApiClient,
+pub fn query_database_properties<T: HttpTransport>(
+    client: &NotionApiClient<T>,
     database_id: &str,
-) -> NotionApiClientResult<Response> {
+) -> NotionApiClientResult<TransportResponse> {
     let path = format!("{}/databases/{}", &client.base_url, database_id);
 
-    client
-        .inner
-        .get(&path)
-        .set_default_headers()
-        .set_authorization_header(&client.api_key)
-        .call()
-        .map_err(api_client_error)
+    client.wait_for_throttle_slot();
+    let result = client.transport.get(&path, &client.default_headers());
+
+    dispatch(client, result)
 }
 
-pub fn query_database(
-    client: &NotionApiClient,
+pub fn query_database<T: HttpTransport>(
+    client: &NotionApiClient<T>,
     parameters: QueryDatabaseParameters,
-) -> NotionApiClientResult<Response> {
+) -> NotionApiClientResult<TransportResponse> {
     let QueryDatabaseParameters {
         database_id,
         start_cursor,
@@ -108,73 +247,221 @@ pub fn query_database(
         body["filter"] = filter;
     }
 
-    client
-        .inner
-        .post(&path)
-        .set_default_headers()
-        .set_authorization_header(&client.api_key)
-        .send_json(body)
-        .map_err(api_client_error)
+    client.wait_for_throttle_slot();
+    let result = client
+        .transport
+        .send_json(HttpMethod::Post, &path, &client.default_headers(), body);
+
+    dispatch(client, result)
 }
 
-pub fn send_with_retries<F, S>(
-    parameters: RetryParameters<S>,
-    f: F,
-) -> NotionApiClientResult<Response>
-where
-    F: Fn() -> NotionApiClientResult<Response>,
-    S: Fn(Duration),
-{
-    let RetryParameters {
-        custom_sleep: sleep_override,
+pub fn get_block_children<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: GetBlockChildrenParameters,
+) -> NotionApiClientResult<ListResponse<Block>> {
+    let GetBlockChildrenParameters {
+        block_id,
+        page_size,
+        start_cursor,
     } = parameters;
 
-    let max_retries = 3;
-    let mut retries = 0;
+    let mut path = format!("{}/blocks/{}/children", &client.base_url, block_id);
+    let mut query = Vec::new();
 
-    loop {
-        let result = f();
+    if let Some(page_size) = page_size {
+        query.push(format!("page_size={}", page_size.get()));
+    }
 
-        if result.is_ok() {
-            return result;
-        }
+    if let Some(start_cursor) = start_cursor {
+        query.push(format!("start_cursor={}", start_cursor));
+    }
 
-        if retries == max_retries {
-            tracing::error!(
-                "Stoping to retry Notion API request after {} retries",
-                max_retries
-            );
+    if !query.is_empty() {
+        path = format!("{}?{}", path, query.join("&"));
+    }
 
-            return result;
-        }
+    client.wait_for_throttle_slot();
+    let result = client.transport.get(&path, &client.default_headers());
+    let response = dispatch(client, result)?;
 
-        retries += 1;
+    response.into_json().map_err(NotionApiClientError::Parse)
+}
 
-        match result.unwrap_err() {
-            NotionApiClientError::RateLimit(duration) => {
-                tracing::warn!(
-                    "Sleeping for {:?} before retrying Notion API request",
-                    duration
-                );
+pub fn append_block_children<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: AppendBlockChildrenParameters,
+) -> NotionApiClientResult<ListResponse<Block>> {
+    let AppendBlockChildrenParameters { block_id, children } = parameters;
+
+    let path = format!("{}/blocks/{}/children", &client.base_url, block_id);
+    let body = serde_json::json!({ "children": children });
+
+    client.wait_for_throttle_slot();
+    let result = client
+        .transport
+        .send_json(HttpMethod::Patch, &path, &client.default_headers(), body);
+    let response = dispatch(client, result)?;
+
+    response.into_json().map_err(NotionApiClientError::Parse)
+}
+
+pub fn search<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: SearchParameters,
+) -> NotionApiClientResult<ListResponse<Object>> {
+    let SearchParameters {
+        query,
+        filter,
+        sort,
+        page_size,
+        start_cursor,
+    } = parameters;
+
+    let path = format!("{}/search", &client.base_url);
+    let mut body = serde_json::json!({});
+
+    if let Some(query) = query {
+        body["query"] = query.into();
+    }
+
+    if let Some(filter) = filter {
+        body["filter"] = filter;
+    }
+
+    if let Some(sort) = sort {
+        body["sort"] = sort;
+    }
+
+    if let Some(page_size) = page_size {
+        body["page_size"] = page_size.get().into();
+    }
+
+    if let Some(start_cursor) = start_cursor {
+        body["start_cursor"] = start_cursor.into();
+    }
+
+    client.wait_for_throttle_slot();
+    let result = client
+        .transport
+        .send_json(HttpMethod::Post, &path, &client.default_headers(), body);
+    let response = dispatch(client, result)?;
+
+    response.into_json().map_err(NotionApiClientError::Parse)
+}
+
+pub fn create_database_entry_typed<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: CreateDatabaseEntryParameters,
+) -> NotionApiClientResult<Page> {
+    create_database_entry(client, parameters)?
+        .into_json()
+        .map_err(NotionApiClientError::Parse)
+}
+
+pub fn query_database_properties_typed<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    database_id: &str,
+) -> NotionApiClientResult<Database> {
+    query_database_properties(client, database_id)?
+        .into_json()
+        .map_err(NotionApiClientError::Parse)
+}
+
+pub fn query_database_typed<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: QueryDatabaseParameters,
+) -> NotionApiClientResult<ListResponse<Page>> {
+    query_database(client, parameters)?
+        .into_json()
+        .map_err(NotionApiClientError::Parse)
+}
+
+pub fn update_database_entry_typed<T: HttpTransport>(
+    client: &NotionApiClient<T>,
+    parameters: UpdateDatabaseEntryParameters,
+) -> NotionApiClientResult<Page> {
+    update_database_entry(client, parameters)?
+        .into_json()
+        .map_err(NotionApiClientError::Parse)
+}
+
+/// Lazily walks every page of a `query_database` result, following Notion's
+/// `has_more`/`next_cursor` pagination and flattening `results` into a single stream.
+pub struct DatabasePages<'a, T: HttpTransport> {
+    client: &'a NotionApiClient<T>,
+    database_id: String,
+    filter: Option<serde_json::Value>,
+    page_size: Option<NonZeroU32>,
+    next_cursor: Option<String>,
+    has_more: bool,
+    started: bool,
+    buffer: std::vec::IntoIter<Page>,
+}
 
-                match &sleep_override {
-                    Some(sleep) => sleep(duration),
-                    None => thread::sleep(duration),
-                };
+impl<'a, T: HttpTransport> Iterator for DatabasePages<'a, T> {
+    type Item = NotionApiClientResult<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(page) = self.buffer.next() {
+                return Some(Ok(page));
+            }
+
+            if self.started && !self.has_more {
+                return None;
             }
-            err => {
-                tracing::warn!("Not retryable Notion API request error: {}", err);
 
-                return Err(err);
+            self.started = true;
+
+            let parameters = QueryDatabaseParameters {
+                database_id: &self.database_id,
+                filter: self.filter.clone(),
+                page_size: self.page_size,
+                start_cursor: self.next_cursor.as_deref(),
+            };
+
+            match query_database_typed(self.client, parameters) {
+                Ok(response) => {
+                    self.has_more = response.has_more;
+                    self.next_cursor = response.next_cursor;
+                    self.buffer = response.results.into_iter();
+                }
+                Err(err) => {
+                    self.has_more = false;
+                    return Some(Err(err));
+                }
             }
         }
     }
 }
 
-pub fn update_database_entry(
-    client: &NotionApiClient,
+pub fn query_database_all<'a, T: HttpTransport>(
+    client: &'a NotionApiClient<T>,
+    parameters: QueryDatabaseParameters<'a>,
+) -> DatabasePages<'a, T> {
+    let QueryDatabaseParameters {
+        database_id,
+        filter,
+        page_size,
+        start_cursor,
+    } = parameters;
+
+    DatabasePages {
+        client,
+        database_id: database_id.to_string(),
+        filter,
+        page_size,
+        next_cursor: start_cursor.map(str::to_string),
+        has_more: true,
+        started: false,
+        buffer: Vec::new().into_iter(),
+    }
+}
+
+pub fn update_database_entry<T: HttpTransport>(
+    client: &NotionApiClient<T>,
     parameters: UpdateDatabaseEntryParameters,
-) -> NotionApiClientResult<Response> {
+) -> NotionApiClientResult<TransportResponse> {
     let UpdateDatabaseEntryParameters {
         entry_id,
         properties,
@@ -183,23 +470,22 @@ pub fn update_database_entry(
     let path = format!("{}/pages/{}", &client.base_url, entry_id);
     let body = serde_json::json!({"properties": properties});
 
-    client
-        .inner
-        .patch(&path)
-        .set_default_headers()
-        .set_authorization_header(&client.api_key)
-        .send_json(body)
-        .map_err(api_client_error)
+    client.wait_for_throttle_slot();
+    let result = client
+        .transport
+        .send_json(HttpMethod::Patch, &path, &client.default_headers(), body);
+
+    dispatch(client, result)
 }
 
 // Integrations should accommodate variable rate limits by handling HTTP 429 responses
 // and respecting the Retry-After response header value,
 // which is set as an integer number of seconds (in decimal).
 // See more for details https://developers.notion.com/reference/request-limits
-fn api_client_error(err: ureq::Error) -> NotionApiClientError {
+fn api_client_error(err: TransportFailure) -> NotionApiClientError {
     match err {
-        ureq::Error::Transport(err) => NotionApiClientError::Transport(err.to_string()),
-        ureq::Error::Status(429, response) => {
+        TransportFailure::Communication(message) => NotionApiClientError::Transport(message),
+        TransportFailure::Status(response) if response.status() == 429 => {
             let retry_after = response.header("Retry-After").unwrap_or_else(|| {
                 tracing::warn!(
                     "Notion API response returned 429 status code without Retry-After header"
@@ -219,7 +505,12 @@ fn api_client_error(err: ureq::Error) -> NotionApiClientError {
 
             NotionApiClientError::RateLimit(duration)
         }
-        ureq::Error::Status(code, _) => NotionApiClientError::Status(code),
+        TransportFailure::Status(response) => {
+            let status = response.status();
+            let body = response.into_json::<NotionErrorBody>().ok();
+
+            NotionApiClientError::Status(status, body)
+        }
     }
 }
 
@@ -277,6 +568,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_database_entry_typed_deserializes_page() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+        let properties = json!({
+            "Name": {"title": [{"text": {"content": "Tuscan Kale"}}]}
+        });
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/pages").method(POST);
+
+            then.status(200).json_body(json!({
+                "id": "test_page_id",
+                "created_time": "2021-05-11T19:26:00.000Z",
+                "last_edited_time": "2021-05-11T19:26:00.000Z",
+                "archived": false,
+                "properties": {},
+                "url": "https://notion.so/test_page_id"
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = create_database_entry_typed(
+            &client,
+            CreateDatabaseEntryParameters {
+                database_id,
+                properties,
+            },
+        )?;
+
+        mock.assert();
+        assert_eq!(result.id.0, "test_page_id");
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_database_properties_returns_status_200() -> Result<()> {
         let mock_notion_server = MockServer::start();
@@ -381,4 +713,503 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_update_database_entry_typed_deserializes_page() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let entry_id = "test_entry_id";
+        let properties = json!({
+            "Name": {"title": [{"text": {"content": "Tuscan Kale"}}]}
+        });
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/pages/test_entry_id").method(PATCH);
+
+            then.status(200).json_body(json!({
+                "id": "test_entry_id",
+                "created_time": "2021-05-11T19:26:00.000Z",
+                "last_edited_time": "2021-05-11T19:26:00.000Z",
+                "archived": false,
+                "properties": {},
+                "url": "https://notion.so/test_entry_id"
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = update_database_entry_typed(
+            &client,
+            UpdateDatabaseEntryParameters {
+                entry_id,
+                properties,
+            },
+        )?;
+
+        mock.assert();
+        assert_eq!(result.id.0, "test_entry_id");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_database_typed_deserializes_pages() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query").method(POST);
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "id": "test_page_id",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "archived": false,
+                    "properties": {},
+                    "url": "https://notion.so/test_page_id"
+                }],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = query_database_typed(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )?;
+
+        mock.assert();
+        assert!(!result.has_more);
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].id.0, "test_page_id");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_database_properties_typed_deserializes_database() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id").method(GET);
+
+            then.status(200).json_body(json!({
+                "id": "test_database_id",
+                "created_time": "2021-05-11T19:26:00.000Z",
+                "last_edited_time": "2021-05-11T19:26:00.000Z",
+                "title": [],
+                "properties": {},
+                "url": "https://notion.so/test_database_id"
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = query_database_properties_typed(&client, database_id)?;
+
+        mock.assert();
+        assert_eq!(result.id.0, "test_database_id");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_database_all_follows_next_cursor() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let first_page = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query")
+                .method(POST)
+                .json_body(json!({"page_size": 100}));
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "id": "page_1",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "archived": false,
+                    "properties": {},
+                    "url": null
+                }],
+                "has_more": true,
+                "next_cursor": "cursor_1"
+            }));
+        });
+
+        let second_page = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query")
+                .method(POST)
+                .json_body(json!({"page_size": 100, "start_cursor": "cursor_1"}));
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "id": "page_2",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "archived": false,
+                    "properties": {},
+                    "url": null
+                }],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let pages = query_database_all(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .collect::<NotionApiClientResult<Vec<_>>>()?;
+
+        first_page.assert();
+        second_page.assert();
+        assert_eq!(
+            pages.into_iter().map(|page| page.id.0).collect::<Vec<_>>(),
+            vec!["page_1", "page_2"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_database_all_accepts_parameters_borrowed_from_a_local_string() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query").method(POST);
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        // `database_id` and `filter` are owned locally, rather than `'static` string
+        // literals, so this exercises `DatabasePages`' borrow of `&'a NotionApiClient<T>`
+        // and `QueryDatabaseParameters<'a>` over a lifetime shorter than `'static`.
+        let database_id = String::from("test_database_id");
+        let filter = serde_json::json!({"property": "Status"});
+
+        let pages = query_database_all(
+            &client,
+            QueryDatabaseParameters {
+                database_id: &database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: Some(filter),
+            },
+        )
+        .collect::<NotionApiClientResult<Vec<_>>>()?;
+
+        mock.assert();
+        assert!(pages.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_returns_mixed_pages_and_databases() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/search")
+                .method(POST)
+                .header("Authorization", "Bearer test_api_key")
+                .header("Content-Type", "application/json")
+                .header("Notion-Version", "2022-06-28")
+                .json_body(json!({"query": "Tuscan Kale"}));
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "object": "page",
+                    "id": "test_page_id",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "archived": false,
+                    "properties": {},
+                    "url": null
+                }, {
+                    "object": "database",
+                    "id": "test_database_id",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "title": [],
+                    "properties": {},
+                    "url": null
+                }],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = search(
+            &client,
+            SearchParameters {
+                query: Some("Tuscan Kale"),
+                ..Default::default()
+            },
+        )?;
+
+        mock.assert();
+        assert_eq!(result.results.len(), 2);
+        assert!(matches!(result.results[0], Object::Page(_)));
+        assert!(matches!(result.results[1], Object::Database(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interceptor_can_add_a_header_before_dispatch() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id")
+                .method(GET)
+                .header("X-Request-Id", "request-123");
+
+            then.status(200);
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        })
+        .with_interceptor(|headers| {
+            headers.push(("X-Request-Id".to_string(), "request-123".to_string()));
+        });
+
+        let result = query_database_properties(&client, database_id);
+
+        mock.assert();
+        assert_eq!(result?.status(), 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_error_preserves_notion_error_body() {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id").method(GET);
+
+            then.status(404).json_body(json!({
+                "object": "error",
+                "status": 404,
+                "code": "object_not_found",
+                "message": "Could not find database with ID: test_database_id."
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let err = query_database_properties(&client, database_id).unwrap_err();
+
+        mock.assert();
+
+        match err {
+            NotionApiClientError::Status(404, Some(body)) => {
+                assert_eq!(body.code, "object_not_found");
+                assert_eq!(
+                    body.message,
+                    "Could not find database with ID: test_database_id."
+                );
+            }
+            other => panic!("expected a Status(404, Some(_)) error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_get_block_children_returns_blocks() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let block_id = "test_block_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/blocks/test_block_id/children").method(GET);
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "id": "test_child_block_id",
+                    "type": "paragraph",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "has_children": false,
+                    "archived": false,
+                    "paragraph": {"rich_text": []}
+                }],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = get_block_children(
+            &client,
+            GetBlockChildrenParameters {
+                block_id,
+                page_size: None,
+                start_cursor: None,
+            },
+        )?;
+
+        mock.assert();
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].kind, "paragraph");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_limit_throttling_sleeps_for_the_minimum_spacing() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query").method(POST);
+            then.status(200);
+        });
+
+        let min_spacing = std::time::Duration::from_millis(50);
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        })
+        .with_rate_limit_throttling(min_spacing);
+
+        let query = || {
+            query_database(
+                &client,
+                QueryDatabaseParameters {
+                    database_id,
+                    page_size: None,
+                    start_cursor: None,
+                    filter: None,
+                },
+            )
+        };
+
+        query()?;
+        let started_at = std::time::Instant::now();
+        query()?;
+        let elapsed = started_at.elapsed();
+
+        mock.assert_hits(2);
+        assert!(
+            elapsed >= min_spacing,
+            "expected at least {:?} between requests, waited {:?}",
+            min_spacing,
+            elapsed
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_block_children_returns_appended_blocks() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let block_id = "test_block_id";
+        let children = json!([{
+            "paragraph": {"rich_text": [{"text": {"content": "Hello"}}]}
+        }]);
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/blocks/test_block_id/children")
+                .method(PATCH)
+                .json_body(json!({"children": children}));
+
+            then.status(200).json_body(json!({
+                "object": "list",
+                "results": [{
+                    "id": "test_child_block_id",
+                    "type": "paragraph",
+                    "created_time": "2021-05-11T19:26:00.000Z",
+                    "last_edited_time": "2021-05-11T19:26:00.000Z",
+                    "has_children": false,
+                    "archived": false,
+                    "paragraph": {"rich_text": [{"text": {"content": "Hello"}}]}
+                }],
+                "has_more": false,
+                "next_cursor": null
+            }));
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let result = append_block_children(&client, AppendBlockChildrenParameters { block_id, children })?;
+
+        mock.assert();
+        assert_eq!(result.results.len(), 1);
+        assert_eq!(result.results[0].id.0, "test_child_block_id");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+use serde_json::Value as Json;
+
+pub mod ids {
+    use serde::{Deserialize, Serialize};
+    use std::fmt;
+
+    macro_rules! notion_id {
+        ($name:ident) => {
+            #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+            pub struct $name(pub String);
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl AsRef<str> for $name {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+        };
+    }
+
+    notion_id!(PageId);
+    notion_id!(DatabaseId);
+    notion_id!(BlockId);
+}
+
+use ids::{BlockId, DatabaseId, PageId};
+
+/// A Notion page, e.g. a row returned by `query_database` or `create_database_entry`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Page {
+    pub id: PageId,
+    pub created_time: String,
+    pub last_edited_time: String,
+    pub archived: bool,
+    pub properties: Json,
+    pub url: Option<String>,
+}
+
+/// A Notion database, e.g. returned by `query_database_properties`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Database {
+    pub id: DatabaseId,
+    pub created_time: String,
+    pub last_edited_time: String,
+    pub title: Json,
+    pub properties: Json,
+    pub url: Option<String>,
+}
+
+/// The paginated envelope Notion wraps list-shaped responses in
+/// (`query_database`, `search`, block children, ...).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListResponse<T> {
+    pub object: String,
+    pub results: Vec<T>,
+    pub has_more: bool,
+    pub next_cursor: Option<String>,
+}
+
+/// A result item that can be either a page or a database, as returned by `/search`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum Object {
+    Page(Page),
+    Database(Database),
+}
+
+/// A block of page content, e.g. a paragraph, heading, or to-do returned by
+/// `get_block_children` or `append_block_children`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Block {
+    pub id: BlockId,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub created_time: String,
+    pub last_edited_time: String,
+    pub has_children: bool,
+    pub archived: bool,
+
+    #[serde(flatten)]
+    pub content: Json,
+}
@@ -1,12 +1,23 @@
 use serde_json::Value as Json;
 use std::num::NonZeroU32;
 
+pub struct AppendBlockChildrenParameters<'a> {
+    pub block_id: &'a str,
+    pub children: Json,
+}
+
 pub struct CreateDatabaseEntryParameters<'a> {
     pub database_id: &'a str,
     pub properties: Json,
 }
 
-pub struct ClientParameters {
+pub struct GetBlockChildrenParameters<'a> {
+    pub block_id: &'a str,
+    pub page_size: Option<NonZeroU32>,
+    pub start_cursor: Option<&'a str>,
+}
+
+pub struct NotionApiClientParameters {
     pub api_key: String,
     pub base_url_override: Option<String>,
 }
@@ -19,8 +30,12 @@ pub struct QueryDatabaseParameters<'a> {
 }
 
 #[derive(Default)]
-pub struct RetryParameters<F> {
-    pub custom_sleep: Option<F>,
+pub struct SearchParameters<'a> {
+    pub query: Option<&'a str>,
+    pub filter: Option<Json>,
+    pub sort: Option<Json>,
+    pub page_size: Option<NonZeroU32>,
+    pub start_cursor: Option<&'a str>,
 }
 
 pub struct UpdateDatabaseEntryParameters<'a> {
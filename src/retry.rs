@@ -0,0 +1,220 @@
+use crate::{NotionApiClientError, NotionApiClientResult, TransportResponse};
+use rand::Rng;
+use std::time::Duration;
+
+/// Decides whether a failed request should be retried, and whether Notion told us
+/// exactly how long to wait before trying again.
+pub trait RetryPolicy {
+    fn should_retry(&self, error: &NotionApiClientError) -> bool;
+    fn backoff_hint(&self, error: &NotionApiClientError) -> Option<Duration>;
+}
+
+/// Retries communication failures and rate limits, but never status errors, since
+/// retrying those can't succeed without the caller changing something.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRetryPolicy;
+
+impl RetryPolicy for DefaultRetryPolicy {
+    fn should_retry(&self, error: &NotionApiClientError) -> bool {
+        error.is_communication() || error.is_rate_limit()
+    }
+
+    fn backoff_hint(&self, error: &NotionApiClientError) -> Option<Duration> {
+        error.retry_after()
+    }
+}
+
+/// Exponential backoff with full jitter, driven by a [`RetryPolicy`]. The backoff
+/// is capped at `max_backoff`, except when the policy's `backoff_hint` returns a
+/// value (e.g. a `Retry-After`-derived rate-limit duration), which is honored as-is.
+pub struct RetryConfig<P = DefaultRetryPolicy> {
+    pub policy: P,
+    pub base: Duration,
+    pub max_backoff: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for RetryConfig<DefaultRetryPolicy> {
+    fn default() -> Self {
+        Self {
+            policy: DefaultRetryPolicy,
+            base: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(32),
+            max_retries: 5,
+        }
+    }
+}
+
+impl<P: RetryPolicy> RetryConfig<P> {
+    pub(crate) fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base
+            .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+        let capped = exponential.min(self.max_backoff);
+
+        Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=capped.as_secs_f64()))
+    }
+}
+
+/// Replaces the crate's earlier fixed-retry-count `send_with_retries`/`RetryParameters`
+/// with a pluggable [`RetryPolicy`] and exponential backoff, so callers can retry
+/// communication failures as well as rate limits, and tune the backoff curve.
+pub fn send_with_retries<P: RetryPolicy>(
+    config: &RetryConfig<P>,
+    sleep: impl Fn(Duration),
+    f: impl Fn() -> NotionApiClientResult<TransportResponse>,
+) -> NotionApiClientResult<TransportResponse> {
+    let mut attempt = 0;
+
+    loop {
+        let err = match f() {
+            Ok(response) => return Ok(response),
+            Err(err) => err,
+        };
+
+        if attempt >= config.max_retries || !config.policy.should_retry(&err) {
+            tracing::warn!("Not retrying Notion API request error: {}", err);
+
+            return Err(err);
+        }
+
+        let delay = config
+            .policy
+            .backoff_hint(&err)
+            .unwrap_or_else(|| config.backoff_for_attempt(attempt));
+
+        tracing::warn!(
+            "Sleeping for {:?} before retrying Notion API request",
+            delay
+        );
+
+        sleep(delay);
+
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NotionApiClient, NotionApiClientParameters, QueryDatabaseParameters};
+    use anyhow::Result;
+    use httpmock::MockServer;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    #[test]
+    fn test_default_retry_policy_retries_rate_limits_with_the_given_hint() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query");
+            then.status(429).header("Retry-After", "0.01");
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let err = crate::query_database(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .unwrap_err();
+
+        mock.assert();
+
+        let policy = DefaultRetryPolicy;
+        assert!(policy.should_retry(&err));
+        assert_eq!(
+            policy.backoff_hint(&err),
+            Some(Duration::from_secs_f64(0.01))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_retry_policy_never_retries_status_errors() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query");
+            then.status(401);
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let err = crate::query_database(
+            &client,
+            QueryDatabaseParameters {
+                database_id,
+                page_size: None,
+                start_cursor: None,
+                filter: None,
+            },
+        )
+        .unwrap_err();
+
+        mock.assert();
+
+        assert!(!DefaultRetryPolicy.should_retry(&err));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_send_with_retries_returns_status_200() -> Result<()> {
+        let mock_notion_server = MockServer::start();
+        let base_url = mock_notion_server.base_url();
+        let database_id = "test_database_id";
+
+        let mock = mock_notion_server.mock(|when, then| {
+            when.path("/databases/test_database_id/query");
+            then.status(200);
+        });
+
+        let client = NotionApiClient::new(NotionApiClientParameters {
+            base_url_override: Some(base_url),
+            api_key: "test_api_key".to_string(),
+        });
+
+        let sleep_count = AtomicU8::new(0);
+
+        let result = send_with_retries(
+            &RetryConfig::default(),
+            |_duration| {
+                sleep_count.fetch_add(1, Ordering::SeqCst);
+            },
+            || {
+                crate::query_database(
+                    &client,
+                    QueryDatabaseParameters {
+                        database_id,
+                        page_size: None,
+                        start_cursor: None,
+                        filter: None,
+                    },
+                )
+            },
+        );
+
+        mock.assert();
+        assert_eq!(result?.status(), 200);
+        assert_eq!(sleep_count.load(Ordering::SeqCst), 0);
+
+        Ok(())
+    }
+}
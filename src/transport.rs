@@ -0,0 +1,149 @@
+use std::io::Read;
+use ureq::Agent;
+
+/// The HTTP method used by a write request dispatched through an [`HttpTransport`].
+pub enum HttpMethod {
+    Post,
+    Patch,
+}
+
+/// A normalized, transport-agnostic response: a status code, its headers, and a
+/// lazily-readable body.
+pub struct TransportResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Box<dyn Read + Send>,
+}
+
+impl std::fmt::Debug for TransportResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransportResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TransportResponse {
+    pub fn new(status: u16, headers: Vec<(String, String)>, body: Box<dyn Read + Send>) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn into_json<T: serde::de::DeserializeOwned>(self) -> std::io::Result<T> {
+        serde_json::from_reader(self.body)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// Raised by an [`HttpTransport`] when a request could not be completed, or completed
+/// with a non-2xx status.
+pub enum TransportFailure {
+    Communication(String),
+    Status(TransportResponse),
+}
+
+/// Abstracts the HTTP backend `NotionApiClient` is built on, so it can run over
+/// something other than a blocking `ureq::Agent` (WASI transports, test doubles,
+/// async runtimes via a blocking shim, etc).
+pub trait HttpTransport {
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, TransportFailure>;
+
+    fn send_json(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &[(String, String)],
+        body: serde_json::Value,
+    ) -> Result<TransportResponse, TransportFailure>;
+}
+
+/// The default [`HttpTransport`], backed by a blocking `ureq::Agent`.
+pub struct UreqTransport {
+    inner: Agent,
+}
+
+impl Default for UreqTransport {
+    fn default() -> Self {
+        Self {
+            inner: Agent::new(),
+        }
+    }
+}
+
+impl HttpTransport for UreqTransport {
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, TransportFailure> {
+        let mut request = self.inner.get(url);
+
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+
+        request.call().map(ureq_response).map_err(ureq_failure)
+    }
+
+    fn send_json(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &[(String, String)],
+        body: serde_json::Value,
+    ) -> Result<TransportResponse, TransportFailure> {
+        let mut request = match method {
+            HttpMethod::Post => self.inner.post(url),
+            HttpMethod::Patch => self.inner.patch(url),
+        };
+
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+
+        request
+            .send_json(body)
+            .map(ureq_response)
+            .map_err(ureq_failure)
+    }
+}
+
+fn ureq_response(response: ureq::Response) -> TransportResponse {
+    let status = response.status();
+    let headers = response
+        .headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_string();
+            Some((name, value))
+        })
+        .collect();
+
+    TransportResponse::new(status, headers, Box::new(response.into_reader()))
+}
+
+fn ureq_failure(err: ureq::Error) -> TransportFailure {
+    match err {
+        ureq::Error::Transport(err) => TransportFailure::Communication(err.to_string()),
+        ureq::Error::Status(_, response) => TransportFailure::Status(ureq_response(response)),
+    }
+}